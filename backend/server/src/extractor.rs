@@ -0,0 +1,90 @@
+use axum::{async_trait, extract::FromRequestParts, response::IntoResponse};
+use chrono::Utc;
+use hyper::{http::request::Parts, StatusCode};
+use uchat_domain::ids::{SessionId, UserId};
+use uchat_query::session;
+
+use crate::{
+    fingerprint::{self, ClientFingerprint},
+    AppState,
+};
+
+/// A checked-out connection from `state.db_pool`.
+pub struct DbConnection(pub uchat_query::AsyncConnection);
+
+#[async_trait]
+impl FromRequestParts<AppState> for DbConnection {
+    type Rejection = SessionRejection;
+
+    async fn from_request_parts(_parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        state.db_pool.get().map(DbConnection).map_err(|_| SessionRejection)
+    }
+}
+
+/// An authenticated request: the client presented a session id + signature
+/// (via `x-session-id`/`x-session-signature`) that verifies against
+/// `state.signing_keys`, is not expired, and whose recomputed fingerprint
+/// still matches what was bound at login.
+pub struct UserSession {
+    pub user_id: UserId,
+    pub session_id: SessionId,
+}
+
+pub struct SessionRejection;
+
+impl IntoResponse for SessionRejection {
+    fn into_response(self) -> axum::response::Response {
+        StatusCode::UNAUTHORIZED.into_response()
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for UserSession {
+    type Rejection = SessionRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let session_id: SessionId = parts
+            .headers
+            .get("x-session-id")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
+            .ok_or(SessionRejection)?;
+
+        let signature = parts
+            .headers
+            .get("x-session-signature")
+            .and_then(|value| value.to_str().ok())
+            .ok_or(SessionRejection)?;
+
+        let DbConnection(mut conn) = DbConnection::from_request_parts(parts, state)
+            .await
+            .map_err(|_| SessionRejection)?;
+
+        let session = session::get(&mut conn, session_id).map_err(|_| SessionRejection)?;
+
+        let decoded_signature = uchat_crypto::decode_base64(signature).map_err(|_| SessionRejection)?;
+        state
+            .signing_keys
+            .verify(session_id.as_uuid().as_bytes(), &decoded_signature)
+            .map_err(|_| SessionRejection)?;
+
+        if session.expires_at < Utc::now() {
+            return Err(SessionRejection);
+        }
+
+        // `FromRequestParts::Rejection = Infallible` for `ClientFingerprint`,
+        // so this can't actually fail.
+        let ClientFingerprint(current) = ClientFingerprint::from_request_parts(parts, state)
+            .await
+            .unwrap_or_else(|never| match never {});
+
+        if !fingerprint::matches(&session.fingerprint, &current, state.fingerprint_policy) {
+            return Err(SessionRejection);
+        }
+
+        Ok(UserSession {
+            user_id: session.user_id,
+            session_id: session.id,
+        })
+    }
+}
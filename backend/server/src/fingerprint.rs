@@ -0,0 +1,137 @@
+use std::net::{IpAddr, Ipv4Addr};
+
+use axum::{async_trait, extract::FromRequestParts};
+use hyper::{header, http::request::Parts};
+use serde_json::Value;
+
+use crate::AppState;
+
+/// How strictly a stored fingerprint must match the current request before a
+/// session is accepted. `IpSubnetOnly` accepts a new address inside the same
+/// /24 (IPv4) or /64 (IPv6) as the bound one; a move to an unrelated network,
+/// such as wifi to cellular, still fails the check.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FingerprintPolicy {
+    Exact,
+    IpSubnetOnly,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fingerprint {
+    pub ip: IpAddr,
+    pub user_agent: Option<String>,
+}
+
+impl Fingerprint {
+    pub fn as_json(&self) -> Value {
+        serde_json::json!({
+            "ip": self.ip.to_string(),
+            "user_agent": self.user_agent,
+        })
+    }
+}
+
+/// Extractor that reads the peer IP and `User-Agent` off the incoming
+/// request.
+///
+/// `X-Forwarded-For`/`Forwarded` are attacker-controlled on any connection
+/// that didn't come through one of our own reverse proxies, so they're only
+/// consulted when the TCP peer itself (`ConnectInfo`) is in
+/// `state.trusted_proxies`. Everyone else's forwarded headers are ignored and
+/// the raw peer IP is used instead.
+pub struct ClientFingerprint(pub Fingerprint);
+
+#[async_trait]
+impl FromRequestParts<AppState> for ClientFingerprint {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let direct_ip = peer_ip(parts).unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+
+        let from_trusted_proxy = state
+            .trusted_proxies
+            .iter()
+            .any(|trusted| *trusted == direct_ip);
+
+        let ip = if from_trusted_proxy {
+            forwarded_ip(parts).unwrap_or(direct_ip)
+        } else {
+            direct_ip
+        };
+
+        let user_agent = parts
+            .headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+
+        Ok(ClientFingerprint(Fingerprint { ip, user_agent }))
+    }
+}
+
+fn forwarded_ip(parts: &Parts) -> Option<IpAddr> {
+    if let Some(value) = parts.headers.get("x-forwarded-for").and_then(|v| v.to_str().ok()) {
+        if let Some(ip) = value.split(',').next().and_then(|ip| ip.trim().parse().ok()) {
+            return Some(ip);
+        }
+    }
+
+    parts
+        .headers
+        .get("forwarded")
+        .and_then(|value| value.to_str().ok())
+        .and_then(forwarded_for_param)
+}
+
+/// Pulls the address out of a `Forwarded` header's `for=` parameter, e.g.
+/// `for=192.0.2.60;proto=http` or `for="[2001:db8::1]"`.
+fn forwarded_for_param(value: &str) -> Option<IpAddr> {
+    value.split(';').find_map(|part| {
+        let (key, value) = part.trim().split_once('=')?;
+        if !key.eq_ignore_ascii_case("for") {
+            return None;
+        }
+        value.trim().trim_matches('"').trim_start_matches('[').trim_end_matches(']').parse().ok()
+    })
+}
+
+fn peer_ip(parts: &Parts) -> Option<IpAddr> {
+    parts
+        .extensions
+        .get::<axum::extract::ConnectInfo<std::net::SocketAddr>>()
+        .map(|connect_info| connect_info.0.ip())
+}
+
+/// Recomputes whether `current` still satisfies the fingerprint stored at
+/// session creation, per `policy`. Called by the `UserSession` extractor on
+/// every authenticated request; a `false` result should be rejected with
+/// `401 Unauthorized`.
+///
+/// The user-agent comparison runs under every policy: `IpSubnetOnly` only
+/// loosens how closely the IP has to match, not whether the device binding is
+/// checked at all.
+pub fn matches(stored: &Value, current: &Fingerprint, policy: FingerprintPolicy) -> bool {
+    let stored_ip: Option<IpAddr> = stored
+        .get("ip")
+        .and_then(Value::as_str)
+        .and_then(|ip| ip.parse().ok());
+
+    let ip_matches = match (stored_ip, policy) {
+        (Some(stored_ip), FingerprintPolicy::Exact) => stored_ip == current.ip,
+        (Some(stored_ip), FingerprintPolicy::IpSubnetOnly) => same_subnet(stored_ip, current.ip),
+        (None, _) => false,
+    };
+
+    let ua_matches =
+        stored.get("user_agent").and_then(Value::as_str) == current.user_agent.as_deref();
+
+    ip_matches && ua_matches
+}
+
+fn same_subnet(a: IpAddr, b: IpAddr) -> bool {
+    match (a, b) {
+        (IpAddr::V4(a), IpAddr::V4(b)) => u32::from(a) & 0xffff_ff00 == u32::from(b) & 0xffff_ff00,
+        (IpAddr::V6(a), IpAddr::V6(b)) => a.octets()[..8] == b.octets()[..8],
+        _ => false,
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use axum::{Json, async_trait};
+use chrono::Utc;
+use hyper::StatusCode;
+use uchat_endpoint::dm::{
+    endpoint::{
+        FetchConversation, FetchConversationOk, ListConversations, ListConversationsOk, SendDm,
+        SendDmOk,
+    },
+    types::{ConversationPreview, PublicDm},
+};
+use uchat_endpoint::user::types::PublicUserProfile;
+use uchat_query::{dm, AsyncConnection};
+use uchat_domain::ids::*;
+
+use crate::{extractor::{DbConnection, UserSession}, error::ApiResult, AppState};
+
+use super::{user, PublicApiRequest};
+
+fn to_public(message: uchat_query::dm::Dm, from_user: PublicUserProfile) -> PublicDm {
+    PublicDm {
+        id: message.id,
+        from_user,
+        to_user: message.to_user,
+        content: message.content,
+        time_sent: message.time_sent,
+    }
+}
+
+#[async_trait]
+impl PublicApiRequest for SendDm {
+    type Response = (StatusCode, Json<SendDmOk>);
+    async fn process_request(
+        self,
+        DbConnection(mut conn): DbConnection,
+        session: UserSession,
+        _state: AppState,
+    ) -> ApiResult<Self::Response> {
+        let dm_id = dm::new(&mut conn, session.user_id, self.to_user, &self.content)?;
+
+        Ok((
+            StatusCode::OK,
+            Json(SendDmOk {
+                dm_id,
+                time_sent: Utc::now(),
+            }),
+        ))
+    }
+}
+
+#[async_trait]
+impl PublicApiRequest for FetchConversation {
+    type Response = (StatusCode, Json<FetchConversationOk>);
+    async fn process_request(
+        self,
+        DbConnection(mut conn): DbConnection,
+        session: UserSession,
+        _state: AppState,
+    ) -> ApiResult<Self::Response> {
+        // A conversation only ever has two participants, so resolve both
+        // profiles once up front instead of once per message.
+        let own_profile = user::to_public(uchat_query::user::get(&mut conn, session.user_id)?)?;
+        let peer_profile = user::to_public(uchat_query::user::get(&mut conn, self.peer_id)?)?;
+
+        let messages = dm::get_conversation(&mut conn, session.user_id, self.peer_id)?
+            .into_iter()
+            .map(|message| {
+                let from_user = if message.from_user == session.user_id {
+                    own_profile.clone()
+                } else {
+                    peer_profile.clone()
+                };
+                to_public(message, from_user)
+            })
+            .collect();
+
+        Ok((
+            StatusCode::OK,
+            Json(FetchConversationOk {
+                peer_id: self.peer_id,
+                messages,
+            }),
+        ))
+    }
+}
+
+#[async_trait]
+impl PublicApiRequest for ListConversations {
+    type Response = (StatusCode, Json<ListConversationsOk>);
+    async fn process_request(
+        self,
+        DbConnection(mut conn): DbConnection,
+        session: UserSession,
+        _state: AppState,
+    ) -> ApiResult<Self::Response> {
+        let previews = dm::list_conversations(&mut conn, session.user_id)?;
+
+        let own_profile = user::to_public(uchat_query::user::get(&mut conn, session.user_id)?)?;
+
+        // One profile fetch per distinct peer, not per conversation/message.
+        let mut peer_profiles: HashMap<UserId, PublicUserProfile> = HashMap::new();
+        for preview in &previews {
+            if !peer_profiles.contains_key(&preview.peer_id) {
+                let profile = user::to_public(uchat_query::user::get(&mut conn, preview.peer_id)?)?;
+                peer_profiles.insert(preview.peer_id, profile);
+            }
+        }
+
+        let conversations = previews
+            .into_iter()
+            .map(|preview| {
+                let from_user = if preview.last_message.from_user == session.user_id {
+                    own_profile.clone()
+                } else {
+                    peer_profiles[&preview.peer_id].clone()
+                };
+                ConversationPreview {
+                    peer_id: preview.peer_id,
+                    last_message: to_public(preview.last_message, from_user),
+                }
+            })
+            .collect();
+
+        Ok((StatusCode::OK, Json(ListConversationsOk { conversations })))
+    }
+}
@@ -0,0 +1,69 @@
+use axum::{Json, async_trait};
+use hyper::StatusCode;
+use uchat_endpoint::post::endpoint::{Boost, BoostOk, React, ReactOk, Unreact, UnreactOk};
+use uchat_query::{post::reaction, AsyncConnection};
+
+use crate::{extractor::{DbConnection, UserSession}, error::ApiResult, AppState};
+
+use super::PublicApiRequest;
+
+// NOTE: this file only covers the write path for reactions/boosts. Rendering
+// `PublicPost.like_count`/`boost_count`/`self_reacted`/`self_boosted` on a
+// fresh page load depends on the post-listing/fetch query joining
+// `post_reaction` per viewer — that query isn't part of this checkout, so it
+// isn't touched here. Whoever owns that handler needs to populate those
+// fields the same way the `FetchConversation`/`ListConversations` handlers in
+// `handler/dm.rs` resolve profiles: once per request, not per post.
+
+#[async_trait]
+impl PublicApiRequest for React {
+    type Response = (StatusCode, Json<ReactOk>);
+    async fn process_request(
+        self,
+        DbConnection(mut conn): DbConnection,
+        session: UserSession,
+        _state: AppState,
+    ) -> ApiResult<Self::Response> {
+        reaction::add(&mut conn, self.post_id, session.user_id, self.reaction)?;
+        let like_count = reaction::count(&mut conn, self.post_id)?;
+
+        Ok((StatusCode::OK, Json(ReactOk { like_count })))
+    }
+}
+
+#[async_trait]
+impl PublicApiRequest for Unreact {
+    type Response = (StatusCode, Json<UnreactOk>);
+    async fn process_request(
+        self,
+        DbConnection(mut conn): DbConnection,
+        session: UserSession,
+        _state: AppState,
+    ) -> ApiResult<Self::Response> {
+        reaction::remove(&mut conn, self.post_id, session.user_id, self.reaction)?;
+        let like_count = reaction::count(&mut conn, self.post_id)?;
+
+        Ok((StatusCode::OK, Json(UnreactOk { like_count })))
+    }
+}
+
+#[async_trait]
+impl PublicApiRequest for Boost {
+    type Response = (StatusCode, Json<BoostOk>);
+    async fn process_request(
+        self,
+        DbConnection(mut conn): DbConnection,
+        session: UserSession,
+        state: AppState,
+    ) -> ApiResult<Self::Response> {
+        // `state.boost_guard` makes a replayed boost (double-click, a second
+        // tab, a direct request replay) a no-op: only the first claim for
+        // this (post, user) pair actually writes.
+        if state.boost_guard.try_claim(self.post_id, session.user_id).await {
+            reaction::boost(&mut conn, self.post_id, session.user_id)?;
+        }
+        let boost_count = reaction::boost_count(&mut conn, self.post_id)?;
+
+        Ok((StatusCode::OK, Json(BoostOk { boost_count })))
+    }
+}
@@ -4,9 +4,9 @@ use hyper::StatusCode;
 use tracing::info;
 use uchat_endpoint::user::{endpoint::{CreateUser, CreateUserOk, Login, LoginOk}, types::PublicUserProfile};
 use uchat_query::{session::Session, user::User, AsyncConnection};
-use uchat_domain::{ids::*, user::DisplayName};
+use uchat_domain::{ids::*, user::{BirthDate, DisplayName}, Headline};
 
-use crate::{extractor::{DbConnection, UserSession}, AppState, error::ApiResult};
+use crate::{extractor::{DbConnection, UserSession}, fingerprint::{ClientFingerprint, Fingerprint}, rate_limit::{ClientKey, LimitType}, AppState, error::ApiResult};
 
 use super::PublicApiRequest;
 
@@ -22,6 +22,7 @@ pub fn to_public(user: User) -> ApiResult<PublicUserProfile> {
             profile_image: None,
             created_at: user.created_at,
             am_following: false,
+            headline: user.headline.and_then(|headline| Headline::new(headline).ok()),
         }
     )
 }
@@ -30,8 +31,9 @@ fn new_session(
     state: &AppState,
     conn: &mut uchat_query::AsyncConnection,
     user_id: UserId,
+    fingerprint: &Fingerprint,
 ) -> ApiResult<(Session, SessionSignature, Duration)> {
-        let fingerprint = serde_json::json!({});
+        let fingerprint = fingerprint.as_json();
         let session_duration = Duration::weeks(3);
         let session: Session = uchat_query::session::new(
             conn,
@@ -52,17 +54,40 @@ fn new_session(
 
 #[async_trait]
 impl PublicApiRequest for CreateUser {
-    type Response = (StatusCode, Json<CreateUserOk>);
+    type Response = axum::response::Response;
     async fn process_request(
         self,
         DbConnection(mut conn): DbConnection,
         state: AppState,
+        ClientFingerprint(fingerprint): ClientFingerprint,
     ) -> ApiResult<Self::Response> {
+        use axum::response::IntoResponse;
+
+        if let Err(exceeded) = state
+            .rate_limiter
+            .check(ClientKey::Ip(fingerprint.ip), LimitType::AuthRegister, &state.rate_limit_config)
+            .await
+        {
+            return Ok(exceeded.into_response());
+        }
+
+        // `birth_date` is required so the age gate can't be skipped by simply
+        // omitting it; display name, email, and headline stay optional.
+        let birth_date = BirthDate::new(self.birth_date)?;
+
         let password_hash = uchat_crypto::hash_password(&self.password)?;
-        let user_id = uchat_query::user::new(&mut conn, password_hash, &self.username)?;
+        let user_id = uchat_query::user::new(
+            &mut conn,
+            password_hash,
+            &self.username,
+            self.display_name.as_ref(),
+            self.email.as_deref(),
+            birth_date,
+            self.headline.as_ref(),
+        )?;
         info!(username = self.username.as_ref(), "new user created");
 
-        let (session, signature, duration) = new_session(&state, &mut conn, user_id)?;
+        let (session, signature, duration) = new_session(&state, &mut conn, user_id, &fingerprint)?;
 
         Ok((
             StatusCode::CREATED,
@@ -72,34 +97,46 @@ impl PublicApiRequest for CreateUser {
                 session_signature: signature.0,
                 session_id: session.id,
                 session_expires: Utc::now() + duration,
-            })
-        ))
+            }),
+        )
+            .into_response())
     }
 }
 
 
 #[async_trait]
 impl PublicApiRequest for Login {
-    type Response = (StatusCode, Json<LoginOk>);
+    type Response = axum::response::Response;
     async fn process_request(
         self,
         DbConnection(mut conn): DbConnection,
         state: AppState,
+        ClientFingerprint(fingerprint): ClientFingerprint,
     ) -> ApiResult<Self::Response> {
-        let _span = 
+        use axum::response::IntoResponse;
+
+        if let Err(exceeded) = state
+            .rate_limiter
+            .check(ClientKey::Ip(fingerprint.ip), LimitType::AuthLogin, &state.rate_limit_config)
+            .await
+        {
+            return Ok(exceeded.into_response());
+        }
+
+        let _span =
             tracing::span!(tracing::Level::INFO, "logging in",
             user = %self.username.as_ref())
         .entered();
         let hash = uchat_query::user::get_password_hash(&mut conn, &self.username)?;
         let hash = uchat_crypto::password::deserialize_hash(&hash)?;
-        
+
         uchat_crypto::verify_password(self.password, &hash)?;
-        
+
         let user = uchat_query::user::find(&mut conn, &self.username)?;
 
-        let (session, signature, duration) = new_session(&state, &mut conn, user.id)?;
+        let (session, signature, duration) = new_session(&state, &mut conn, user.id, &fingerprint)?;
 
-             Ok((
+        Ok((
             StatusCode::OK,
             Json(LoginOk {
                 session_id: session.id,
@@ -107,9 +144,12 @@ impl PublicApiRequest for Login {
                 session_signature: signature.0,
                 display_name: user.display_name,
                 email: user.email,
+                birth_date: user.birth_date.and_then(|date| BirthDate::new(date).ok()),
+                headline: user.headline.and_then(|headline| Headline::new(headline).ok()),
                 profile_image: None,
                 user_id: user.id,
             }),
-        ))
+        )
+            .into_response())
     }
 }
\ No newline at end of file
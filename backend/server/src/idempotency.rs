@@ -0,0 +1,54 @@
+use std::collections::HashSet;
+
+use tokio::sync::RwLock;
+use uchat_domain::ids::{PostId, UserId};
+
+/// Tracks `(post, user)` pairs that have already boosted a post, so a
+/// replayed `Boost` request — double-click, a second tab, a direct
+/// `curl` replay, or a genuine race — is a no-op instead of inflating the
+/// count. This sits on top of whatever `reaction::boost` itself enforces at
+/// the DB level: it is an in-memory layer, not a replacement for that
+/// enforcement.
+#[derive(Default)]
+pub struct BoostGuard {
+    boosted: RwLock<HashSet<(PostId, UserId)>>,
+}
+
+impl BoostGuard {
+    /// Claims the boost for `(post_id, user_id)`. Returns `true` the first
+    /// time this pair is seen, `false` on every call after that.
+    pub async fn try_claim(&self, post_id: PostId, user_id: UserId) -> bool {
+        self.boosted.write().await.insert((post_id, user_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn second_boost_from_the_same_user_is_a_no_op() {
+        let guard = BoostGuard::default();
+        let post_id = PostId::new();
+        let user_id = UserId::new();
+
+        assert!(guard.try_claim(post_id, user_id).await, "first boost should be claimed");
+        assert!(
+            !guard.try_claim(post_id, user_id).await,
+            "replaying the same boost must not be claimed twice"
+        );
+        assert!(
+            !guard.try_claim(post_id, user_id).await,
+            "a third replay must also be rejected"
+        );
+    }
+
+    #[tokio::test]
+    async fn different_users_can_each_boost_the_same_post() {
+        let guard = BoostGuard::default();
+        let post_id = PostId::new();
+
+        assert!(guard.try_claim(post_id, UserId::new()).await);
+        assert!(guard.try_claim(post_id, UserId::new()).await);
+    }
+}
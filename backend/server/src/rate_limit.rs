@@ -0,0 +1,144 @@
+use std::{collections::HashMap, net::IpAddr};
+
+use chrono::{DateTime, Duration, Utc};
+use tokio::sync::RwLock;
+use uchat_domain::ids::UserId;
+
+/// A class of request that gets its own counter and ceiling, so login can be
+/// throttled harder than a read-only endpoint.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum LimitType {
+    AuthLogin,
+    AuthRegister,
+    PostCreate,
+}
+
+impl LimitType {
+    pub fn window_len(&self, config: &RateLimitConfig) -> Duration {
+        config.class(*self).window
+    }
+
+    pub fn ceiling(&self, config: &RateLimitConfig) -> u32 {
+        config.class(*self).ceiling
+    }
+}
+
+/// Per-class window length and request ceiling, read from `AppState` so
+/// deployments can tune login throttling separately from reads.
+#[derive(Clone, Copy, Debug)]
+pub struct LimitClass {
+    pub window: Duration,
+    pub ceiling: u32,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RateLimitConfig {
+    pub auth_login: LimitClass,
+    pub auth_register: LimitClass,
+    pub post_create: LimitClass,
+}
+
+impl RateLimitConfig {
+    fn class(&self, limit_type: LimitType) -> LimitClass {
+        match limit_type {
+            LimitType::AuthLogin => self.auth_login,
+            LimitType::AuthRegister => self.auth_register,
+            LimitType::PostCreate => self.post_create,
+        }
+    }
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            auth_login: LimitClass {
+                window: Duration::minutes(1),
+                ceiling: 5,
+            },
+            auth_register: LimitClass {
+                window: Duration::hours(1),
+                ceiling: 3,
+            },
+            post_create: LimitClass {
+                window: Duration::minutes(1),
+                ceiling: 30,
+            },
+        }
+    }
+}
+
+/// Identifies who is being rate limited: the signed-in user if there is a
+/// session, otherwise the peer IP.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub enum ClientKey {
+    User(UserId),
+    Ip(IpAddr),
+}
+
+struct Window {
+    window_start: DateTime<Utc>,
+    count: u32,
+}
+
+pub struct RateLimitExceeded {
+    pub retry_after: Duration,
+}
+
+impl RateLimitExceeded {
+    /// `429 Too Many Requests` with a `Retry-After` header in whole seconds.
+    pub fn into_response(self) -> axum::response::Response {
+        use axum::response::IntoResponse;
+
+        let retry_after = self.retry_after.num_seconds().max(0).to_string();
+        (
+            hyper::StatusCode::TOO_MANY_REQUESTS,
+            [(hyper::header::RETRY_AFTER, retry_after)],
+        )
+            .into_response()
+    }
+}
+
+/// Fixed-window request counter keyed by `(ClientKey, LimitType)`, lives on
+/// `AppState` as `state.rate_limiter` and is consulted at the top of
+/// `process_request` for `CreateUser`, `Login`, and post-create, ahead of any
+/// other work.
+#[derive(Default)]
+pub struct RateLimiter {
+    windows: RwLock<HashMap<(ClientKey, LimitType), Window>>,
+}
+
+impl RateLimiter {
+    pub async fn check(
+        &self,
+        client_key: ClientKey,
+        limit_type: LimitType,
+        config: &RateLimitConfig,
+    ) -> Result<(), RateLimitExceeded> {
+        let now = Utc::now();
+        let window_len = limit_type.window_len(config);
+        let ceiling = limit_type.ceiling(config);
+
+        let mut windows = self.windows.write().await;
+        let window = windows
+            .entry((client_key, limit_type))
+            .or_insert_with(|| Window {
+                window_start: now,
+                count: 0,
+            });
+
+        if now - window.window_start >= window_len {
+            window.window_start = now;
+            window.count = 0;
+        }
+
+        window.count += 1;
+
+        if window.count > ceiling {
+            return Err(RateLimitExceeded {
+                retry_after: window.window_start + window_len - now,
+            });
+        }
+
+        Ok(())
+    }
+}
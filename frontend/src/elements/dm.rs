@@ -0,0 +1,92 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use fermi::{UseAtomRef, use_atom_ref};
+use indexmap::IndexMap;
+use uchat_domain::ids::UserId;
+use uchat_endpoint::dm::types::PublicDm;
+
+pub fn use_dm_manager(cx: &ScopeState) -> &UseAtomRef<DmManager> {
+    use_atom_ref(cx, crate::app::DMMANAGER)
+}
+
+/// Keyed by peer id, matching `FetchConversationOk`
+/// (`uchat_endpoint::dm::endpoint`) and `ConversationPreview`
+/// (`uchat_endpoint::dm::types`), which identify a conversation by the
+/// other participant rather than a separate conversation id.
+#[derive(Default)]
+pub struct DmManager {
+    pub conversations: IndexMap<UserId, Vec<PublicDm>>,
+}
+
+impl DmManager {
+    pub fn update<F>(&mut self, peer_id: UserId, mut update_fn: F) -> bool
+    where
+        F: FnMut(&mut Vec<PublicDm>),
+    {
+        if let Some(conversation) = self.conversations.get_mut(&peer_id) {
+            update_fn(conversation);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn populate<T>(&mut self, conversations: T)
+    where
+        T: Iterator<Item = (UserId, Vec<PublicDm>)>,
+    {
+        self.conversations.clear();
+        for (peer_id, messages) in conversations {
+            self.conversations.insert(peer_id, messages);
+        }
+    }
+
+    pub fn get(&self, peer_id: &UserId) -> Option<&Vec<PublicDm>> {
+        self.conversations.get(peer_id)
+    }
+
+    pub fn clear(&mut self) {
+        self.conversations.clear();
+    }
+}
+
+#[inline_props]
+pub fn ConversationEntry(cx: Scope, peer_id: UserId) -> Element {
+    let dm_manager = use_dm_manager(cx);
+
+    let messages = {
+        let messages = dm_manager.read().get(&peer_id).unwrap().clone();
+        use_state(cx, || messages)
+    };
+
+    cx.render(rsx! {
+        div {
+            key: "{peer_id.to_string()}",
+            class: "grid grid-cols-[50px_1fr] gap-2 mb-4",
+            div { },
+            div {
+                class: "flex flex-col gap-3",
+                messages.get().iter().map(|dm| {
+                    let display_name = match &dm.from_user.display_name {
+                        Some(name) => name.as_ref(),
+                        None => &dm.from_user.handle,
+                    };
+                    rsx! {
+                        div {
+                            key: "{dm.id.to_string()}",
+                            class: "flex flex-col",
+                            div {
+                                class: "flex flex-row justify-between",
+                                div { class: "cursor-pointer", "{display_name}" },
+                                div { class: "text-right font-light", "{dm.time_sent.format(\"%H:%M:%S\")}" }
+                            },
+                            div { "{dm.content.as_ref()}" }
+                        }
+                    }
+                }),
+                hr {},
+            }
+        }
+    })
+}
@@ -4,8 +4,10 @@ use crate::{prelude::*, elements::post::content::Content};
 use dioxus::prelude::*;
 use fermi::{UseAtomRef, use_atom_ref};
 use indexmap::IndexMap;
+use serde::Serialize;
 use uchat_domain::ids::PostId;
-use uchat_endpoint::post::types::PublicPost;
+use uchat_endpoint::post::endpoint::{Boost as BoostRequest, React, Unreact};
+use uchat_endpoint::post::types::{PublicPost, ReactionKind};
 
 pub mod content;
 
@@ -91,6 +93,126 @@ pub fn Header<'a>(cx: Scope<'a>, post: &'a PublicPost) -> Element {
     })
 }
 
+/// Sends a typed endpoint request body, same as the rest of the API surface
+/// — keeps the client in sync with whatever `req` actually deserializes into
+/// on the server instead of hand-rolling a JSON shape that can silently drift.
+async fn send_reaction<T: Serialize>(endpoint: &str, req: &T) -> Result<(), reqwest::Error> {
+    reqwest::Client::new()
+        .post(endpoint)
+        .json(req)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+
+#[inline_props]
+pub fn ActionBar<'a>(cx: Scope<'a>, post: &'a UseState<PublicPost>) -> Element<'a> {
+    let post_manager = use_post_manager(cx);
+
+    let toggle_like = move |_| {
+        let post_id = post.get().id;
+        let now_liked = !post.get().self_reacted;
+
+        post.with_mut(|post| {
+            post.like_count = if now_liked {
+                post.like_count + 1
+            } else {
+                post.like_count.saturating_sub(1)
+            };
+            post.self_reacted = now_liked;
+        });
+        post_manager.write().update(post_id, |cached| {
+            cached.like_count = post.get().like_count;
+            cached.self_reacted = post.get().self_reacted;
+        });
+
+        cx.spawn({
+            to_owned![post, post_manager];
+            async move {
+                let sent = if now_liked {
+                    send_reaction(
+                        "/api/post/react",
+                        &React { post_id, reaction: ReactionKind::Like },
+                    )
+                    .await
+                } else {
+                    send_reaction(
+                        "/api/post/unreact",
+                        &Unreact { post_id, reaction: ReactionKind::Like },
+                    )
+                    .await
+                };
+                if sent.is_err() {
+                    post.with_mut(|post| {
+                        post.like_count = if now_liked {
+                            post.like_count.saturating_sub(1)
+                        } else {
+                            post.like_count + 1
+                        };
+                        post.self_reacted = !now_liked;
+                    });
+                    post_manager.write().update(post_id, |cached| {
+                        cached.like_count = post.get().like_count;
+                        cached.self_reacted = post.get().self_reacted;
+                    });
+                }
+            }
+        });
+    };
+
+    let boost = move |_| {
+        if post.get().self_boosted {
+            return;
+        }
+
+        let post_id = post.get().id;
+
+        post.with_mut(|post| {
+            post.boost_count += 1;
+            post.self_boosted = true;
+        });
+        post_manager.write().update(post_id, |cached| {
+            cached.boost_count = post.get().boost_count;
+            cached.self_boosted = post.get().self_boosted;
+        });
+
+        cx.spawn({
+            to_owned![post, post_manager];
+            async move {
+                if send_reaction("/api/post/boost", &BoostRequest { post_id }).await.is_err() {
+                    post.with_mut(|post| {
+                        post.boost_count = post.boost_count.saturating_sub(1);
+                        post.self_boosted = false;
+                    });
+                    post_manager.write().update(post_id, |cached| {
+                        cached.boost_count = post.get().boost_count;
+                        cached.self_boosted = post.get().self_boosted;
+                    });
+                }
+            }
+        });
+    };
+
+    cx.render(rsx! {
+        div {
+            class: "flex flex-row gap-6 text-sm font-light",
+            button {
+                class: "flex items-center gap-1",
+                onclick: toggle_like,
+                if post.get().self_reacted { "♥" } else { "♡" },
+                " {post.get().like_count}"
+            },
+            button {
+                class: "flex items-center gap-1",
+                disabled: post.get().self_boosted,
+                onclick: boost,
+                "⟲ {post.get().boost_count}"
+            }
+        }
+    })
+}
+
 #[inline_props]
 pub fn PublicPostEntry(cx: Scope, post_id: PostId) -> Element {
     let post_manager = use_post_manager(cx);
@@ -111,7 +233,7 @@ pub fn PublicPostEntry(cx: Scope, post_id: PostId) -> Element {
                 Header { post: this_post},
                 //reply to
                 Content { post: this_post },
-                //action bar
+                ActionBar { post: this_post },
                 hr {},
             }
         }
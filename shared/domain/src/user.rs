@@ -0,0 +1,72 @@
+use std::fmt;
+
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::UserFacingError;
+
+pub const MIN_AGE_YEARS: u32 = 13;
+
+/// A user's birth date. `nutype`'s `validate` attribute only checks the raw
+/// value, but this needs to compare against the current date, so it can't
+/// use `nutype` like `Headline`/`Message` do. `Deserialize` is hand-written
+/// below instead of derived so that validation still runs on every
+/// deserialization path, the same way `nutype` bakes it into its generated
+/// impl.
+#[derive(Clone, Copy, Debug, Serialize, PartialEq, Eq)]
+pub struct BirthDate(NaiveDate);
+
+impl<'de> Deserialize<'de> for BirthDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let date = NaiveDate::deserialize(deserializer)?;
+        BirthDate::new(date).map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BirthDateError {
+    InFuture,
+    TooYoung,
+}
+
+impl BirthDate {
+    pub fn new(date: NaiveDate) -> Result<Self, BirthDateError> {
+        let today = Utc::now().date_naive();
+
+        if date > today {
+            return Err(BirthDateError::InFuture);
+        }
+
+        if today.years_since(date).unwrap_or(0) < MIN_AGE_YEARS {
+            return Err(BirthDateError::TooYoung);
+        }
+
+        Ok(BirthDate(date))
+    }
+}
+
+impl AsRef<NaiveDate> for BirthDate {
+    fn as_ref(&self) -> &NaiveDate {
+        &self.0
+    }
+}
+
+impl UserFacingError for BirthDateError {
+    fn formatted_error(&self) -> &'static str {
+        match self {
+            BirthDateError::InFuture => "Birth date cannot be in the future.",
+            BirthDateError::TooYoung => "You must be at least 13 years old to register.",
+        }
+    }
+}
+
+impl fmt::Display for BirthDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.formatted_error())
+    }
+}
+
+impl std::error::Error for BirthDateError {}